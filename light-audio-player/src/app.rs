@@ -0,0 +1,78 @@
+use std::{path::PathBuf, time::Duration};
+
+use crate::audio::PlayerEvent;
+use crate::queue::Queue;
+
+/// Playback state shared by every front-end (TUI, REPL, ...) driving the
+/// audio subsystem.
+pub struct App {
+    pub current_track: Option<String>,
+    pub playing: bool,
+    pub elapsed: Duration,
+    pub duration: Duration,
+    pub volume: f32,
+    /// Most recent visualizer magnitude bins reported by the audio worker.
+    pub recent_magnitudes: Vec<f32>,
+    pub queue: Queue,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self {
+            current_track: None,
+            playing: false,
+            elapsed: Duration::from_secs(0),
+            duration: Duration::from_secs(0),
+            volume: 1.0,
+            recent_magnitudes: Vec::new(),
+            queue: Queue::new(),
+        }
+    }
+
+    /// Loads the selected queue entry as the current track.
+    pub fn load_selected(&mut self) -> Option<PathBuf> {
+        let path = self.queue.selected_track()?.to_path_buf();
+        self.current_track = Some(path.display().to_string());
+        self.playing = true;
+        self.elapsed = Duration::from_secs(0);
+        Some(path)
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.playing = !self.playing;
+    }
+
+    pub fn seek(&mut self, delta: i64) {
+        let secs = self.elapsed.as_secs() as i64 + delta;
+        self.elapsed = Duration::from_secs(secs.max(0) as u64);
+    }
+
+    pub fn adjust_volume(&mut self, delta: f32) {
+        self.volume = (self.volume + delta).clamp(0.0, 1.0);
+    }
+
+    /// Folds an update reported by the audio worker into UI state. Returns
+    /// the next track to load when the queue auto-advances past a track
+    /// that just ended.
+    pub fn apply_event(&mut self, event: PlayerEvent) -> Option<PathBuf> {
+        match event {
+            PlayerEvent::Position(pos) => {
+                self.elapsed = pos;
+                None
+            }
+            PlayerEvent::Duration(duration) => {
+                self.duration = duration;
+                None
+            }
+            PlayerEvent::Samples(magnitudes) => {
+                self.recent_magnitudes = magnitudes;
+                None
+            }
+            PlayerEvent::TrackEnded => {
+                self.playing = false;
+                self.queue.advance()?;
+                self.load_selected()
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Audio subsystem running on its own worker thread.
+//!
+//! There is no real decoder or output sink here yet: `Load` never checks
+//! that the path exists, and position/duration/the visualizer spectrum are
+//! all simulated from wall-clock time rather than decoded audio. What this
+//! module does deliver is the concurrency shape a real decoder would need
+//! — a dedicated thread, a command/event channel pair, and a clean
+//! shutdown/join — so the render loop is never blocked on playback. Treat
+//! this as a fake backend to build the rest of the player against, not a
+//! player that can actually play audio.
+
+use std::{
+    path::PathBuf,
+    sync::mpsc::{Receiver, Sender},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+/// Commands sent from the UI thread to the audio worker.
+pub enum PlayerCommand {
+    Load(PathBuf),
+    Play,
+    Pause,
+    Seek(Duration),
+    SetVolume(f32),
+    Shutdown,
+}
+
+/// Updates sent back from the audio worker, drained by the UI loop each tick.
+pub enum PlayerEvent {
+    Position(Duration),
+    Duration(Duration),
+    TrackEnded,
+    /// Normalized (0.0-1.0) magnitude bins for the visualizer, most recent
+    /// audio first.
+    Samples(Vec<f32>),
+}
+
+/// Stand-in track length used until a real decoder can report duration.
+const SIMULATED_TRACK_DURATION: Duration = Duration::from_secs(180);
+
+/// Number of bins reported per `PlayerEvent::Samples` update.
+const VISUALIZER_BINS: usize = 32;
+
+/// Holds the simulated playback state (track, position, volume) for the
+/// currently loaded track. It runs on its own thread so the render loop
+/// never blocks on playback.
+struct Worker {
+    commands: Receiver<PlayerCommand>,
+    events: Sender<PlayerEvent>,
+    track: Option<PathBuf>,
+    duration: Duration,
+    playing: bool,
+    position: Duration,
+    volume: f32,
+    last_tick: Instant,
+}
+
+impl Worker {
+    fn new(commands: Receiver<PlayerCommand>, events: Sender<PlayerEvent>) -> Self {
+        Self {
+            commands,
+            events,
+            track: None,
+            duration: SIMULATED_TRACK_DURATION,
+            playing: false,
+            position: Duration::from_secs(0),
+            volume: 1.0,
+            last_tick: Instant::now(),
+        }
+    }
+
+    fn run(mut self) {
+        loop {
+            match self.commands.recv_timeout(Duration::from_millis(50)) {
+                Ok(PlayerCommand::Load(path)) => {
+                    self.track = Some(path);
+                    self.position = Duration::from_secs(0);
+                    self.duration = SIMULATED_TRACK_DURATION;
+                    self.playing = true;
+                    let _ = self.events.send(PlayerEvent::Duration(self.duration));
+                }
+                Ok(PlayerCommand::Play) => self.playing = true,
+                Ok(PlayerCommand::Pause) => self.playing = false,
+                Ok(PlayerCommand::Seek(pos)) => self.position = pos,
+                Ok(PlayerCommand::SetVolume(vol)) => self.volume = vol.clamp(0.0, 1.0),
+                Ok(PlayerCommand::Shutdown) => return,
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => return,
+            }
+
+            self.advance();
+        }
+    }
+
+    /// Advances playback position based on elapsed wall-clock time and
+    /// reports it upstream. Stands in for real decoder progress. Once
+    /// `position` reaches the (simulated) track duration, reports
+    /// `TrackEnded` instead of continuing to advance.
+    fn advance(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_tick);
+        self.last_tick = now;
+
+        if !self.playing || self.track.is_none() {
+            return;
+        }
+
+        if self.position >= self.duration {
+            self.playing = false;
+            self.track = None;
+            let _ = self.events.send(PlayerEvent::TrackEnded);
+            return;
+        }
+
+        self.position = (self.position + elapsed).min(self.duration);
+        let _ = self.events.send(PlayerEvent::Position(self.position));
+        let _ = self.events.send(PlayerEvent::Samples(self.magnitudes()));
+    }
+
+    /// Placeholder spectrum derived from playback position until a real
+    /// decoder/FFT pipeline is wired in.
+    fn magnitudes(&self) -> Vec<f32> {
+        let t = self.position.as_secs_f32();
+        (0..VISUALIZER_BINS)
+            .map(|bin| {
+                let phase = t * 3.0 + bin as f32 * 0.4;
+                (phase.sin() * 0.5 + 0.5) * self.volume
+            })
+            .collect()
+    }
+}
+
+/// Spawns the audio worker on its own thread. The caller keeps the command
+/// `Sender` and event `Receiver`; sending `PlayerCommand::Shutdown` and then
+/// joining the returned handle stops playback cleanly.
+pub fn spawn(commands: Receiver<PlayerCommand>, events: Sender<PlayerEvent>) -> JoinHandle<()> {
+    thread::spawn(move || Worker::new(commands, events).run())
+}
@@ -0,0 +1,15 @@
+use std::{
+    io,
+    sync::mpsc::{Receiver, Sender},
+};
+
+use crate::app::App;
+use crate::audio::{PlayerCommand, PlayerEvent};
+
+/// A user-facing front-end that drives the shared audio subsystem over its
+/// command/event channels. The TUI and REPL each implement this so `main`
+/// can dispatch to either without knowing the details of how it presents
+/// playback state.
+pub trait Frontend {
+    fn run(self, app: App, commands: Sender<PlayerCommand>, events: Receiver<PlayerEvent>) -> io::Result<()>;
+}
@@ -1,25 +1,62 @@
-use std::{io, thread, time::Duration};
-use tui::{
-    backend::CrosstermBackend,
-    widgets::{Widget, Block, Borders},
-    layout::{Layout, Constraint, Direction},
-    Terminal, Frame
-};
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
-
-fn ui<B: CrosstermBackend>(f: &mut Frame<B>){
-    
+mod app;
+mod audio;
+mod frontend;
+mod queue;
+mod repl_frontend;
+mod tui_frontend;
+mod visualizer;
+
+use std::{io, path::PathBuf, sync::mpsc, time::Duration};
+
+use clap::{Parser, Subcommand};
+
+use app::App;
+use audio::PlayerCommand;
+use frontend::Frontend;
+use queue::Queue;
+use repl_frontend::ReplFrontend;
+use tui_frontend::TuiFrontend;
+
+#[derive(Parser)]
+#[command(name = "light-audio-player")]
+struct Cli {
+    #[command(subcommand)]
+    mode: Option<Mode>,
+
+    /// Recursively scan this directory for tracks and add them to the queue
+    /// on startup, alongside whatever was persisted from the last session.
+    #[arg(long, global = true)]
+    enqueue: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Mode {
+    /// Full-screen terminal UI (default).
+    Tui,
+    /// Line-oriented REPL for scripting and piping.
+    Repl,
 }
 
-fn main() -> Result<(), io::Error> {
-    enable_raw_mode()?;
-    let stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-    Ok(())
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+
+    let mut app = App::new();
+    app.queue = Queue::load(&Queue::default_path())?;
+    if let Some(dir) = &cli.enqueue {
+        app.queue.enqueue_dir(dir)?;
+    }
+
+    let (command_tx, command_rx) = mpsc::channel();
+    let (event_tx, event_rx) = mpsc::channel();
+    let worker = audio::spawn(command_rx, event_tx);
+
+    let result = match cli.mode.unwrap_or(Mode::Tui) {
+        Mode::Tui => TuiFrontend::new(Duration::from_millis(250)).run(app, command_tx.clone(), event_rx),
+        Mode::Repl => ReplFrontend::new().run(app, command_tx.clone(), event_rx),
+    };
+
+    let _ = command_tx.send(PlayerCommand::Shutdown);
+    let _ = worker.join();
+
+    result
 }
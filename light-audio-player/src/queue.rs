@@ -0,0 +1,252 @@
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+/// File extensions recognized as playable tracks when scanning a directory.
+const SUPPORTED_EXTENSIONS: [&str; 5] = ["mp3", "flac", "wav", "ogg", "m4a"];
+
+/// A single queued track.
+pub struct TrackEntry {
+    pub path: PathBuf,
+}
+
+/// Ordered playback queue with a selected index, rendered as a scrollable
+/// list in the TUI and driven by the REPL's `enqueue`/`next` commands.
+pub struct Queue {
+    pub entries: Vec<TrackEntry>,
+    pub selected: usize,
+}
+
+impl Queue {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    /// Recursively scans `dir` for supported audio files and appends them
+    /// to the queue, sorted by path.
+    pub fn enqueue_dir(&mut self, dir: &Path) -> io::Result<()> {
+        let mut found = Vec::new();
+        visit_dir(dir, &mut found)?;
+        found.sort();
+        self.entries.extend(found.into_iter().map(|path| TrackEntry { path }));
+        Ok(())
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = (self.selected + 1) % self.entries.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = self.selected.checked_sub(1).unwrap_or(self.entries.len() - 1);
+        }
+    }
+
+    pub fn selected_track(&self) -> Option<&Path> {
+        self.entries.get(self.selected).map(|entry| entry.path.as_path())
+    }
+
+    /// Advances to the next entry, wrapping at the end of the queue, and
+    /// returns the new track to load. Called when the audio worker reports
+    /// that the current track ended.
+    pub fn advance(&mut self) -> Option<&Path> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        self.selected = (self.selected + 1) % self.entries.len();
+        self.selected_track()
+    }
+
+    /// Default location for the persisted queue: `$XDG_CONFIG_HOME` (or
+    /// `~/.config`) `/light-audio-player/queue.txt`.
+    pub fn default_path() -> PathBuf {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .unwrap_or_else(|| PathBuf::from("."));
+        base.join("light-audio-player").join("queue.txt")
+    }
+
+    /// Persists the queue as one track path per line, with the selected
+    /// index on the first line.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::File::create(path)?;
+        writeln!(file, "{}", self.selected)?;
+        for entry in &self.entries {
+            writeln!(file, "{}", entry.path.display())?;
+        }
+        Ok(())
+    }
+
+    /// Loads a previously saved queue. Returns an empty queue if `path`
+    /// doesn't exist yet (first run).
+    pub fn load(path: &Path) -> io::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+
+        let file = fs::File::open(path)?;
+        let mut lines = io::BufReader::new(file).lines();
+
+        let selected = lines
+            .next()
+            .transpose()?
+            .and_then(|line| line.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        let mut entries = Vec::new();
+        for line in lines {
+            let line = line?;
+            if !line.is_empty() {
+                entries.push(TrackEntry { path: PathBuf::from(line) });
+            }
+        }
+
+        let selected = if entries.is_empty() { 0 } else { selected.min(entries.len() - 1) };
+        Ok(Self { entries, selected })
+    }
+}
+
+fn visit_dir(dir: &Path, found: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            visit_dir(&path, found)?;
+        } else if is_supported(&path) {
+            found.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_supported(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A scratch directory under the OS temp dir, removed on drop, so tests
+    /// touching the filesystem don't step on each other or leave litter.
+    struct ScratchDir(PathBuf);
+
+    impl ScratchDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("light-audio-player-test-{name}"));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn queue_with(paths: &[&str]) -> Queue {
+        let mut queue = Queue::new();
+        queue.entries = paths.iter().map(|p| TrackEntry { path: PathBuf::from(p) }).collect();
+        queue
+    }
+
+    #[test]
+    fn save_then_load_round_trips_entries_and_selection() {
+        let scratch = ScratchDir::new("save-load-round-trip");
+        let path = scratch.path().join("queue.txt");
+
+        let mut queue = queue_with(&["/music/a.mp3", "/music/b.flac"]);
+        queue.selected = 1;
+        queue.save(&path).unwrap();
+
+        let loaded = Queue::load(&path).unwrap();
+        assert_eq!(loaded.selected, 1);
+        assert_eq!(loaded.entries.len(), 2);
+        assert_eq!(loaded.entries[0].path, PathBuf::from("/music/a.mp3"));
+        assert_eq!(loaded.entries[1].path, PathBuf::from("/music/b.flac"));
+    }
+
+    #[test]
+    fn load_clamps_an_out_of_range_selected_index() {
+        let scratch = ScratchDir::new("load-clamps-selection");
+        let path = scratch.path().join("queue.txt");
+        fs::write(&path, "99\n/music/a.mp3\n").unwrap();
+
+        let loaded = Queue::load(&path).unwrap();
+        assert_eq!(loaded.selected, 0);
+    }
+
+    #[test]
+    fn load_missing_file_returns_an_empty_queue() {
+        let scratch = ScratchDir::new("load-missing-file");
+        let path = scratch.path().join("does-not-exist.txt");
+
+        let loaded = Queue::load(&path).unwrap();
+        assert!(loaded.entries.is_empty());
+        assert_eq!(loaded.selected, 0);
+    }
+
+    #[test]
+    fn advance_wraps_from_the_last_entry_to_the_first() {
+        let mut queue = queue_with(&["a", "b"]);
+        queue.selected = 1;
+
+        let next = queue.advance().unwrap().to_path_buf();
+        assert_eq!(next, PathBuf::from("a"));
+        assert_eq!(queue.selected, 0);
+    }
+
+    #[test]
+    fn advance_on_an_empty_queue_returns_none() {
+        let mut queue = Queue::new();
+        assert!(queue.advance().is_none());
+    }
+
+    #[test]
+    fn select_previous_wraps_from_the_first_entry_to_the_last() {
+        let mut queue = queue_with(&["a", "b", "c"]);
+        queue.selected = 0;
+
+        queue.select_previous();
+        assert_eq!(queue.selected, 2);
+    }
+
+    #[test]
+    fn enqueue_dir_recurses_and_skips_unsupported_extensions() {
+        let scratch = ScratchDir::new("enqueue-dir-recurse");
+        fs::create_dir_all(scratch.path().join("sub")).unwrap();
+        fs::write(scratch.path().join("a.mp3"), b"").unwrap();
+        fs::write(scratch.path().join("notes.txt"), b"").unwrap();
+        fs::write(scratch.path().join("sub").join("b.flac"), b"").unwrap();
+
+        let mut queue = Queue::new();
+        queue.enqueue_dir(scratch.path()).unwrap();
+
+        let names: Vec<_> = queue
+            .entries
+            .iter()
+            .map(|e| e.path.file_name().unwrap().to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(names, vec!["a.mp3", "b.flac"]);
+    }
+}
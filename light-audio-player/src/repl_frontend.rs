@@ -0,0 +1,122 @@
+use std::{
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    thread,
+    time::Duration,
+};
+
+use crate::app::App;
+use crate::audio::{PlayerCommand, PlayerEvent};
+use crate::frontend::Frontend;
+use crate::queue::Queue;
+
+/// How often the main loop checks for a pending stdin line when none has
+/// arrived yet. Bounds how long `PlayerEvent`s can pile up in the channel
+/// while the REPL is sitting idle between commands.
+const STDIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Line-oriented front-end for scripting and piping: reads commands like
+/// `play <path>`, `pause`, `seek 30`, `volume 0.5`, `enqueue <dir>`, `next`,
+/// `quit` from stdin and drives the same audio subsystem as the TUI,
+/// without entering raw mode.
+pub struct ReplFrontend;
+
+impl ReplFrontend {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Frontend for ReplFrontend {
+    fn run(self, mut app: App, commands: Sender<PlayerCommand>, events: Receiver<PlayerEvent>) -> io::Result<()> {
+        let (line_tx, line_rx) = mpsc::channel::<io::Result<String>>();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                if line_tx.send(line).is_err() {
+                    return;
+                }
+            }
+        });
+
+        loop {
+            // Drain on every pass through the loop, not just when a line
+            // arrives, so the audio worker's channel can't pile up while
+            // the REPL is idle waiting on stdin.
+            while let Ok(event) = events.try_recv() {
+                if let Some(next) = app.apply_event(event) {
+                    let _ = commands.send(PlayerCommand::Load(next));
+                }
+            }
+
+            let line = match line_rx.recv_timeout(STDIN_POLL_INTERVAL) {
+                Ok(line) => line?,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            };
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let Some(verb) = parts.next() else { continue };
+            match verb {
+                "play" => match parts.next() {
+                    Some(path) => {
+                        app.current_track = Some(path.to_string());
+                        app.playing = true;
+                        let _ = commands.send(PlayerCommand::Load(PathBuf::from(path)));
+                    }
+                    None => println!("usage: play <path>"),
+                },
+                "pause" => {
+                    app.playing = false;
+                    let _ = commands.send(PlayerCommand::Pause);
+                }
+                "resume" => {
+                    app.playing = true;
+                    let _ = commands.send(PlayerCommand::Play);
+                }
+                "seek" => match parts.next().and_then(|s| s.parse::<u64>().ok()) {
+                    Some(secs) => {
+                        app.elapsed = Duration::from_secs(secs);
+                        let _ = commands.send(PlayerCommand::Seek(app.elapsed));
+                    }
+                    None => println!("usage: seek <seconds>"),
+                },
+                "volume" => match parts.next().and_then(|s| s.parse::<f32>().ok()) {
+                    Some(vol) => {
+                        app.volume = vol.clamp(0.0, 1.0);
+                        let _ = commands.send(PlayerCommand::SetVolume(app.volume));
+                    }
+                    None => println!("usage: volume <0.0-1.0>"),
+                },
+                "enqueue" => match parts.next() {
+                    Some(dir) => {
+                        let before = app.queue.entries.len();
+                        match app.queue.enqueue_dir(&PathBuf::from(dir)) {
+                            Ok(()) => println!("queued {} tracks", app.queue.entries.len() - before),
+                            Err(err) => println!("failed to enqueue {dir}: {err}"),
+                        }
+                    }
+                    None => println!("usage: enqueue <dir>"),
+                },
+                "next" => {
+                    if app.queue.advance().is_some() {
+                        if let Some(path) = app.load_selected() {
+                            let _ = commands.send(PlayerCommand::Load(path));
+                        }
+                    }
+                }
+                "quit" => {
+                    let _ = app.queue.save(&Queue::default_path());
+                    return Ok(());
+                }
+                other => println!("unknown command: {other}"),
+            }
+
+            io::stdout().flush()?;
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Full-screen `crossterm`/`tui` front-end.
+//!
+//! Key bindings: Space toggles play/pause, Left/Right seek, `q` quits,
+//! Enter jumps to the selected queue entry. Up/Down navigate the queue
+//! list now that there's a queue panel to navigate; volume, which they
+//! controlled before the queue panel existed, moved to `+`/`-`.
+
+use std::{
+    io,
+    sync::mpsc::{Receiver, Sender},
+    time::{Duration, Instant},
+};
+use tui::{
+    backend::{Backend, CrosstermBackend},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    layout::{Layout, Constraint, Direction},
+    Terminal, Frame
+};
+use crossterm::{
+    cursor::Show,
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::app::App;
+use crate::audio::{PlayerCommand, PlayerEvent};
+use crate::frontend::Frontend;
+use crate::queue::Queue;
+use crate::visualizer::Visualizer;
+
+/// Restores the terminal to its normal mode. Shared by `TerminalGuard::drop`
+/// and the panic hook so both leave the same clean state behind.
+fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, Show)?;
+    Ok(())
+}
+
+/// Prints a hint for getting a corrupted terminal back to normal, for the
+/// rare case where `restore_terminal` itself fails.
+fn print_restore_failure_hint(err: &io::Error) {
+    eprintln!("failed to restore terminal: {err}");
+    if cfg!(target_os = "windows") {
+        eprintln!("your terminal may be left in a broken state; try opening a new terminal window");
+    } else {
+        eprintln!("your terminal may be left in a broken state; try typing `reset` and pressing enter");
+    }
+}
+
+/// Installs a panic hook that restores the terminal before handing off to
+/// the default hook, so a panic never leaves the user's shell corrupted.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if let Err(err) = restore_terminal() {
+            print_restore_failure_hint(&err);
+        }
+        default_hook(info);
+    }));
+}
+
+/// RAII guard that puts the terminal into raw/alternate-screen mode on
+/// construction and always restores it on drop, even on an early return
+/// or panic unwind.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new() -> io::Result<Self> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        if let Err(err) = restore_terminal() {
+            print_restore_failure_hint(&err);
+        }
+    }
+}
+
+fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
+        .split(f.size());
+
+    let items: Vec<ListItem> = app
+        .queue
+        .entries
+        .iter()
+        .map(|entry| ListItem::new(entry.path.display().to_string()))
+        .collect();
+    let queue_list = List::new(items)
+        .block(Block::default().title("queue").borders(Borders::ALL))
+        .highlight_symbol("> ");
+    let mut queue_state = ListState::default();
+    if !app.queue.entries.is_empty() {
+        queue_state.select(Some(app.queue.selected));
+    }
+    f.render_stateful_widget(queue_list, columns[0], &mut queue_state);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(columns[1]);
+
+    let title = match &app.current_track {
+        Some(track) => format!(
+            "{} [{}s/{}s] vol {:.0}%",
+            track,
+            app.elapsed.as_secs(),
+            app.duration.as_secs(),
+            app.volume * 100.0
+        ),
+        None => "no track loaded".to_string(),
+    };
+    let block = Block::default().title(title).borders(Borders::ALL);
+    f.render_widget(block, chunks[0]);
+
+    let visualizer_area = Block::default().title("visualizer").borders(Borders::ALL);
+    let inner = visualizer_area.inner(chunks[1]);
+    f.render_widget(visualizer_area, chunks[1]);
+    f.render_widget(Visualizer::new(&app.recent_magnitudes), inner);
+}
+
+/// Drives the draw/poll loop until the user quits. Sends playback commands
+/// to the audio worker and drains its events each tick so the render loop
+/// never blocks on decoding or output.
+fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    mut app: App,
+    tick_rate: Duration,
+    commands: Sender<PlayerCommand>,
+    events: Receiver<PlayerEvent>,
+) -> io::Result<()> {
+    let mut last_tick = Instant::now();
+    loop {
+        terminal.draw(|f| ui(f, &app))?;
+
+        let timeout = tick_rate
+            .checked_sub(last_tick.elapsed())
+            .unwrap_or_else(|| Duration::from_secs(0));
+
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                match key.code {
+                    KeyCode::Char('q') => {
+                        let _ = app.queue.save(&Queue::default_path());
+                        return Ok(());
+                    }
+                    KeyCode::Char(' ') => {
+                        app.toggle_pause();
+                        let command = if app.playing { PlayerCommand::Play } else { PlayerCommand::Pause };
+                        let _ = commands.send(command);
+                    }
+                    KeyCode::Left => {
+                        app.seek(-5);
+                        let _ = commands.send(PlayerCommand::Seek(app.elapsed));
+                    }
+                    KeyCode::Right => {
+                        app.seek(5);
+                        let _ = commands.send(PlayerCommand::Seek(app.elapsed));
+                    }
+                    KeyCode::Char('+') => {
+                        app.adjust_volume(0.05);
+                        let _ = commands.send(PlayerCommand::SetVolume(app.volume));
+                    }
+                    KeyCode::Char('-') => {
+                        app.adjust_volume(-0.05);
+                        let _ = commands.send(PlayerCommand::SetVolume(app.volume));
+                    }
+                    KeyCode::Up => app.queue.select_previous(),
+                    KeyCode::Down => app.queue.select_next(),
+                    KeyCode::Enter => {
+                        if let Some(path) = app.load_selected() {
+                            let _ = commands.send(PlayerCommand::Load(path));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if last_tick.elapsed() >= tick_rate {
+            while let Ok(event) = events.try_recv() {
+                if let Some(next) = app.apply_event(event) {
+                    let _ = commands.send(PlayerCommand::Load(next));
+                }
+            }
+            last_tick = Instant::now();
+        }
+    }
+}
+
+/// Full-screen `crossterm`/`tui` front-end. Only this front-end touches raw
+/// mode or the alternate screen.
+pub struct TuiFrontend {
+    tick_rate: Duration,
+}
+
+impl TuiFrontend {
+    pub fn new(tick_rate: Duration) -> Self {
+        Self { tick_rate }
+    }
+}
+
+impl Frontend for TuiFrontend {
+    fn run(self, app: App, commands: Sender<PlayerCommand>, events: Receiver<PlayerEvent>) -> io::Result<()> {
+        install_panic_hook();
+        let _guard = TerminalGuard::new()?;
+
+        let backend = CrosstermBackend::new(io::stdout());
+        let mut terminal = Terminal::new(backend)?;
+
+        run_app(&mut terminal, app, self.tick_rate, commands, events)
+    }
+}
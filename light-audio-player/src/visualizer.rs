@@ -0,0 +1,129 @@
+use tui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::Widget,
+};
+
+/// Sub-cell resolution levels, lowest to highest, used to pack more than
+/// one amplitude step into a single terminal row.
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Renders a live amplitude bar graph of recent sample magnitudes (or FFT
+/// bins), one column per bin, using block characters for sub-cell
+/// resolution and color to indicate intensity.
+pub struct Visualizer<'a> {
+    magnitudes: &'a [f32],
+}
+
+impl<'a> Visualizer<'a> {
+    pub fn new(magnitudes: &'a [f32]) -> Self {
+        Self { magnitudes }
+    }
+
+    fn color_for(level: f32) -> Color {
+        if level > 0.85 {
+            Color::Red
+        } else if level > 0.5 {
+            Color::Yellow
+        } else {
+            Color::Green
+        }
+    }
+}
+
+impl<'a> Widget for Visualizer<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 || self.magnitudes.is_empty() {
+            return;
+        }
+
+        let width = area.width as usize;
+        let height = area.height as usize;
+        let rows_per_cell = LEVELS.len();
+
+        for col in 0..width {
+            // Map this column to a bin, downsampling or repeating as needed.
+            let bin = col * self.magnitudes.len() / width;
+            let magnitude = self.magnitudes[bin.min(self.magnitudes.len() - 1)].clamp(0.0, 1.0);
+
+            let filled_rows = magnitude * (height * rows_per_cell) as f32;
+            let full_rows = (filled_rows as usize) / rows_per_cell;
+            let remainder = (filled_rows as usize) % rows_per_cell;
+            let style = Style::default().fg(Self::color_for(magnitude));
+
+            for row in 0..height {
+                let from_bottom = height - 1 - row;
+                let symbol = if from_bottom < full_rows {
+                    LEVELS[rows_per_cell - 1]
+                } else if from_bottom == full_rows && remainder > 0 {
+                    LEVELS[remainder - 1]
+                } else {
+                    continue;
+                };
+
+                buf.get_mut(area.x + col as u16, area.y + row as u16)
+                    .set_char(symbol)
+                    .set_style(style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered_symbols(magnitudes: &[f32], width: u16, height: u16) -> Vec<Vec<char>> {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        Visualizer::new(magnitudes).render(area, &mut buf);
+        (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|col| buf.get(col, row).symbol.chars().next().unwrap_or(' '))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn zero_magnitude_renders_blank_column() {
+        let rows = rendered_symbols(&[0.0], 1, 1);
+        assert_eq!(rows[0][0], ' ');
+    }
+
+    #[test]
+    fn full_magnitude_fills_every_row_with_the_highest_level() {
+        let rows = rendered_symbols(&[1.0], 1, 2);
+        assert_eq!(rows[0][0], *LEVELS.last().unwrap());
+        assert_eq!(rows[1][0], *LEVELS.last().unwrap());
+    }
+
+    #[test]
+    fn partial_magnitude_picks_a_mid_level_for_the_top_row() {
+        // height 1, rows_per_cell 8: 0.5 magnitude fills exactly 4 sub-rows,
+        // landing on LEVELS[3] with no full rows below it.
+        let rows = rendered_symbols(&[0.5], 1, 1);
+        assert_eq!(rows[0][0], LEVELS[3]);
+    }
+
+    #[test]
+    fn downsamples_more_bins_than_columns() {
+        // Two columns, four bins: each column should pick up one of the
+        // two bins on its side rather than panicking on the index math.
+        let rows = rendered_symbols(&[0.0, 0.0, 1.0, 1.0], 2, 1);
+        assert_eq!(rows[0][0], ' ');
+        assert_eq!(rows[0][1], *LEVELS.last().unwrap());
+    }
+
+    #[test]
+    fn repeats_fewer_bins_than_columns() {
+        // One bin stretched across four columns should fill every column
+        // identically instead of leaving the extra columns blank.
+        let rows = rendered_symbols(&[1.0], 4, 1);
+        for symbol in &rows[0] {
+            assert_eq!(*symbol, *LEVELS.last().unwrap());
+        }
+    }
+}